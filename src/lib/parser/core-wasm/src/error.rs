@@ -0,0 +1,157 @@
+use std::fmt;
+use wasm_bindgen::prelude::*;
+
+/// Structured save-parsing errors for `SaveParser`. A missing SaveBlock2
+/// sector is reported as `MissingSector(0)`; SaveBlock1 sectors missing from
+/// the active slot are not reported and are read as zero-filled data.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SaveError {
+    /// The buffer is smaller than a full two-slot save (128 KB) requires
+    TooSmall,
+    /// A sector's footer signature doesn't match the expected Gen3 marker
+    BadSignature,
+    /// A sector's stored checksum doesn't match its recomputed checksum
+    ChecksumMismatch { sector: u16, expected: u16, got: u16 },
+    /// No valid sector with this logical ID was found in the active slot
+    MissingSector(u16),
+    /// A sector's footer falls outside the buffer
+    TruncatedSector,
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::TooSmall => write!(f, "save data is smaller than a full 128 KB save"),
+            SaveError::BadSignature => write!(f, "sector signature does not match a known Gen3 save"),
+            SaveError::ChecksumMismatch { sector, expected, got } => write!(
+                f,
+                "sector {} checksum mismatch: expected {:#06x}, got {:#06x}",
+                sector, expected, got
+            ),
+            SaveError::MissingSector(id) => write!(f, "sector with logical ID {} not found", id),
+            SaveError::TruncatedSector => write!(f, "sector footer falls outside the save buffer"),
+        }
+    }
+}
+
+impl std::error::Error for SaveError {}
+
+impl From<SaveError> for JsError {
+    fn from(error: SaveError) -> JsError {
+        JsError::new(&error.to_string())
+    }
+}
+
+/// Structured Pokemon-parsing errors, replacing the silent zero-fills and
+/// best-guess clamps `Pokemon`'s accessors used to return on truncated or
+/// corrupt byte buffers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PokemonError {
+    /// The buffer is smaller than the 100-byte fixed Pokemon data structure
+    TooShort,
+    /// The decrypted substructure doesn't match its stored checksum
+    BadChecksum,
+    /// The decrypted substructure's shuffle order doesn't match a valid
+    /// `personality % 24` permutation
+    BadSignature,
+    /// The decrypted species ID is zero, which no real Pokemon has
+    InvalidSpecies,
+    /// The species ID has no entry in `species::BASE_STATS`, so its real base
+    /// stats are unknown and can't be used to detect tampering
+    UnknownSpecies(u16),
+}
+
+impl fmt::Display for PokemonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PokemonError::TooShort => write!(f, "Pokemon data must be at least 100 bytes"),
+            PokemonError::BadChecksum => write!(f, "decrypted substructure checksum does not match the stored checksum"),
+            PokemonError::BadSignature => write!(f, "decrypted substructure shuffle order is invalid"),
+            PokemonError::InvalidSpecies => write!(f, "decrypted species ID is zero"),
+            PokemonError::UnknownSpecies(id) => write!(f, "species {} has no base stats entry", id),
+        }
+    }
+}
+
+impl std::error::Error for PokemonError {}
+
+impl From<PokemonError> for JsError {
+    fn from(error: PokemonError) -> JsError {
+        JsError::new(&error.to_string())
+    }
+}
+
+/// Validity/counter/checksum status for one physical sector
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct SectorStatus {
+    physical_index: u8,
+    id: u16,
+    valid: bool,
+    counter: u32,
+    issue: Option<String>,
+}
+
+#[wasm_bindgen]
+impl SectorStatus {
+    #[wasm_bindgen(constructor)]
+    pub fn new(physical_index: u8, id: u16, valid: bool, counter: u32, issue: Option<String>) -> SectorStatus {
+        SectorStatus { physical_index, id, valid, counter, issue }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn physical_index(&self) -> u8 { self.physical_index }
+
+    #[wasm_bindgen(getter)]
+    pub fn id(&self) -> u16 { self.id }
+
+    #[wasm_bindgen(getter)]
+    pub fn valid(&self) -> bool { self.valid }
+
+    #[wasm_bindgen(getter)]
+    pub fn counter(&self) -> u32 { self.counter }
+
+    #[wasm_bindgen(getter)]
+    pub fn issue(&self) -> Option<String> { self.issue.clone() }
+}
+
+/// A non-destructive report on a save file's health: per-sector validity across
+/// both slots, plus whether the inactive slot is a usable recovery backup.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct SaveIntegrityReport {
+    active_slot: u8,
+    slot1_valid_sectors: u8,
+    slot2_valid_sectors: u8,
+    inactive_slot_recoverable: bool,
+    sectors: Vec<SectorStatus>,
+}
+
+#[wasm_bindgen]
+impl SaveIntegrityReport {
+    #[wasm_bindgen(constructor)]
+    pub fn new(
+        active_slot: u8,
+        slot1_valid_sectors: u8,
+        slot2_valid_sectors: u8,
+        inactive_slot_recoverable: bool,
+        sectors: Vec<SectorStatus>,
+    ) -> SaveIntegrityReport {
+        SaveIntegrityReport { active_slot, slot1_valid_sectors, slot2_valid_sectors, inactive_slot_recoverable, sectors }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn active_slot(&self) -> u8 { self.active_slot }
+
+    #[wasm_bindgen(getter)]
+    pub fn slot1_valid_sectors(&self) -> u8 { self.slot1_valid_sectors }
+
+    #[wasm_bindgen(getter)]
+    pub fn slot2_valid_sectors(&self) -> u8 { self.slot2_valid_sectors }
+
+    #[wasm_bindgen(getter)]
+    pub fn inactive_slot_recoverable(&self) -> bool { self.inactive_slot_recoverable }
+
+    #[wasm_bindgen(getter)]
+    pub fn sectors(&self) -> Vec<SectorStatus> { self.sectors.clone() }
+}
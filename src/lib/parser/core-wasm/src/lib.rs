@@ -17,13 +17,18 @@ macro_rules! console_log {
 
 pub mod types;
 pub mod utils;
+pub mod error;
 pub mod pokemon;
+pub mod species;
 pub mod save_parser;
+#[cfg(feature = "scripting")]
+pub mod scripting;
 
 // Re-export main types for JavaScript consumption
 pub use pokemon::Pokemon;
 pub use save_parser::SaveParser;
-pub use types::{SaveData, PlayTimeData};
+pub use types::{SaveData, PlayTimeData, GameVersion, Language, Gender};
+pub use error::{SaveError, SaveIntegrityReport, SectorStatus, PokemonError};
 
 // Export a simple test function to verify WASM is working
 #[wasm_bindgen]
@@ -1,9 +1,12 @@
-use crate::types::{PokemonOffsets, PokemonStats};
+use crate::types::{Gender, Language, PokemonOffsets, PokemonStats};
 use crate::utils::{
     bytes_to_gba_string, get_pokemon_nature, is_pokemon_shiny, get_shiny_value,
     read_u16_le, read_u32_le, write_u16_le, write_u32_le,
-    calculate_hp_stat, calculate_stat, get_nature_modifier
+    get_nature_modifier, personality_for_nature,
+    decrypt_pokemon, encrypt_pokemon, verify_pokemon_checksum,
 };
+use crate::species::{gender_for, level_for_experience};
+use crate::error::PokemonError;
 use wasm_bindgen::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -19,9 +22,9 @@ impl Pokemon {
     #[wasm_bindgen(constructor)]
     pub fn new(raw_bytes: Vec<u8>) -> Result<Pokemon, JsError> {
         if raw_bytes.len() < 100 {
-            return Err(JsError::new("Pokemon data must be at least 100 bytes"));
+            return Err(PokemonError::TooShort.into());
         }
-        
+
         Ok(Pokemon { raw_bytes })
     }
     
@@ -63,20 +66,20 @@ impl Pokemon {
     
     /// Get Pokemon's nickname
     #[wasm_bindgen(getter)]
-    pub fn nickname(&self) -> String {
+    pub fn nickname(&self) -> Result<String, JsError> {
         let start = PokemonOffsets::NICKNAME;
         let end = start + PokemonOffsets::NICKNAME_LENGTH;
-        let nickname_bytes = &self.raw_bytes[start..end.min(self.raw_bytes.len())];
-        bytes_to_gba_string(nickname_bytes)
+        let nickname_bytes = self.raw_bytes.get(start..end).ok_or(PokemonError::TooShort)?;
+        Ok(bytes_to_gba_string(nickname_bytes, Language::Western))
     }
-    
+
     /// Get Pokemon's Original Trainer name
     #[wasm_bindgen(getter)]
-    pub fn ot_name(&self) -> String {
+    pub fn ot_name(&self) -> Result<String, JsError> {
         let start = PokemonOffsets::OT_NAME;
         let end = start + PokemonOffsets::OT_NAME_LENGTH;
-        let ot_name_bytes = &self.raw_bytes[start..end.min(self.raw_bytes.len())];
-        bytes_to_gba_string(ot_name_bytes)
+        let ot_name_bytes = self.raw_bytes.get(start..end).ok_or(PokemonError::TooShort)?;
+        Ok(bytes_to_gba_string(ot_name_bytes, Language::Western))
     }
     
     /// Get Pokemon's current HP
@@ -165,12 +168,8 @@ impl Pokemon {
     
     /// Get Pokemon's level
     #[wasm_bindgen(getter)]
-    pub fn level(&self) -> u8 {
-        if PokemonOffsets::LEVEL < self.raw_bytes.len() {
-            self.raw_bytes[PokemonOffsets::LEVEL]
-        } else {
-            0
-        }
+    pub fn level(&self) -> Result<u8, JsError> {
+        self.raw_bytes.get(PokemonOffsets::LEVEL).copied().ok_or_else(|| PokemonError::TooShort.into())
     }
     
     /// Set Pokemon's level
@@ -183,12 +182,8 @@ impl Pokemon {
     
     /// Get Pokemon's status condition
     #[wasm_bindgen(getter)]
-    pub fn status(&self) -> u8 {
-        if PokemonOffsets::STATUS < self.raw_bytes.len() {
-            self.raw_bytes[PokemonOffsets::STATUS]
-        } else {
-            0
-        }
+    pub fn status(&self) -> Result<u8, JsError> {
+        self.raw_bytes.get(PokemonOffsets::STATUS).copied().ok_or_else(|| PokemonError::TooShort.into())
     }
     
     /// Set Pokemon's status condition
@@ -204,7 +199,22 @@ impl Pokemon {
     pub fn nature(&self) -> String {
         get_pokemon_nature(self.personality())
     }
-    
+
+    /// Set Pokemon's nature by moving its personality value within its block of
+    /// 25 so that `personality % 25` is the new nature's index. Nature isn't stored
+    /// independently in Gen3, so this can shift shininess/gender, which also
+    /// derive from personality's low byte. The substructure block is re-encrypted
+    /// under the new personality so its contents are kept. Does nothing if
+    /// `nature` isn't recognized.
+    #[wasm_bindgen(setter)]
+    pub fn set_nature(&mut self, nature: String) {
+        if let Some(new_personality) = personality_for_nature(self.personality(), &nature) {
+            let decrypted = self.decrypted();
+            self.set_personality(new_personality);
+            encrypt_pokemon(&mut self.raw_bytes, &decrypted);
+        }
+    }
+
     /// Check if Pokemon is shiny
     #[wasm_bindgen(getter)]
     pub fn is_shiny(&self) -> bool {
@@ -216,7 +226,181 @@ impl Pokemon {
     pub fn shiny_value(&self) -> u16 {
         get_shiny_value(self.personality(), self.ot_id())
     }
-    
+
+    /// Get Pokemon's species ID, decrypted from the substructure block
+    #[wasm_bindgen(getter)]
+    pub fn species_id(&self) -> u16 {
+        read_u16_le(&self.decrypted(), PokemonOffsets::SUB_SPECIES)
+    }
+
+    /// Get Pokemon's held item ID, decrypted from the substructure block
+    #[wasm_bindgen(getter)]
+    pub fn held_item(&self) -> u16 {
+        read_u16_le(&self.decrypted(), PokemonOffsets::SUB_HELD_ITEM)
+    }
+
+    /// Set Pokemon's held item ID, re-encrypting the substructure block
+    #[wasm_bindgen(setter)]
+    pub fn set_held_item(&mut self, value: u16) {
+        self.set_decrypted(|sub| write_u16_le(sub, PokemonOffsets::SUB_HELD_ITEM, value));
+    }
+
+    /// Get Pokemon's total experience points, decrypted from the substructure block
+    #[wasm_bindgen(getter)]
+    pub fn experience(&self) -> u32 {
+        read_u32_le(&self.decrypted(), PokemonOffsets::SUB_EXPERIENCE)
+    }
+
+    /// Get Pokemon's PP Up/Max bonuses, decrypted from the substructure block:
+    /// two bits per move (slots 0-3 from the low bits up), counting how many
+    /// times each move's PP has been boosted
+    #[wasm_bindgen(getter)]
+    pub fn pp_bonuses(&self) -> u8 {
+        self.decrypted()[PokemonOffsets::SUB_PP_BONUSES]
+    }
+
+    /// Get Pokemon's four move IDs, decrypted from the substructure block
+    #[wasm_bindgen(getter)]
+    pub fn moves(&self) -> Vec<u16> {
+        let decrypted = self.decrypted();
+        (0..4)
+            .map(|i| read_u16_le(&decrypted, PokemonOffsets::SUB_MOVES + i * 2))
+            .collect()
+    }
+
+    /// Get Pokemon's current PP for each of its four moves, decrypted from
+    /// the substructure block
+    #[wasm_bindgen(getter)]
+    pub fn move_pp(&self) -> Vec<u8> {
+        let decrypted = self.decrypted();
+        decrypted[PokemonOffsets::SUB_MOVE_PP..PokemonOffsets::SUB_MOVE_PP + 4].to_vec()
+    }
+
+    /// Replace Pokemon's four move IDs, re-encrypting the substructure block.
+    /// Slots beyond `moves.len()` (and all four if `moves` is empty) are left
+    /// untouched; pass all four to fully overwrite the moveset.
+    #[wasm_bindgen]
+    pub fn set_moves(&mut self, moves: Vec<u16>) {
+        self.set_decrypted(|sub| {
+            for (i, &move_id) in moves.iter().take(4).enumerate() {
+                write_u16_le(sub, PokemonOffsets::SUB_MOVES + i * 2, move_id);
+            }
+        });
+    }
+
+    /// Get Pokemon's six individual values (HP/Atk/Def/Spe/SpA/SpD), unpacked
+    /// from the packed 5-bit-per-stat word in the substructure block
+    #[wasm_bindgen(getter)]
+    pub fn ivs(&self) -> Vec<u8> {
+        let packed = read_u32_le(&self.decrypted(), PokemonOffsets::SUB_IV_EGG_ABILITY);
+        (0..6).map(|i| ((packed >> (i * 5)) & 0x1F) as u8).collect()
+    }
+
+    /// Replace Pokemon's six individual values (HP/Atk/Def/Spe/SpA/SpD), each
+    /// clamped to the 5-bit range (0-31), re-encrypting the substructure block
+    /// and preserving the egg flag and hidden ability bit
+    #[wasm_bindgen(setter)]
+    pub fn set_ivs(&mut self, ivs: Vec<u8>) {
+        self.set_decrypted(|sub| {
+            let packed = read_u32_le(sub, PokemonOffsets::SUB_IV_EGG_ABILITY);
+            let mut new_packed = packed & !0x3FFF_FFFF; // keep egg flag (bit 30) and ability bit (bit 31)
+            for (i, &iv) in ivs.iter().take(6).enumerate() {
+                new_packed |= ((iv.min(31) as u32) & 0x1F) << (i * 5);
+            }
+            write_u32_le(sub, PokemonOffsets::SUB_IV_EGG_ABILITY, new_packed);
+        });
+    }
+
+    /// Get Pokemon's six effort values (HP/Atk/Def/Spe/SpA/SpD), decrypted
+    /// from the substructure block
+    #[wasm_bindgen(getter)]
+    pub fn evs(&self) -> Vec<u8> {
+        let decrypted = self.decrypted();
+        decrypted[PokemonOffsets::SUB_EVS..PokemonOffsets::SUB_EVS + 6].to_vec()
+    }
+
+    /// Replace Pokemon's six effort values (HP/Atk/Def/Spe/SpA/SpD), re-encrypting
+    /// the substructure block
+    #[wasm_bindgen(setter)]
+    pub fn set_evs(&mut self, evs: Vec<u8>) {
+        self.set_decrypted(|sub| {
+            for (i, &ev) in evs.iter().take(6).enumerate() {
+                sub[PokemonOffsets::SUB_EVS + i] = ev;
+            }
+        });
+    }
+
+    /// Get Pokemon's six contest condition values (Cool/Beauty/Cute/Smart/Tough/Feel),
+    /// decrypted from the substructure block
+    #[wasm_bindgen(getter)]
+    pub fn contest_stats(&self) -> Vec<u8> {
+        let decrypted = self.decrypted();
+        decrypted[PokemonOffsets::SUB_CONTEST..PokemonOffsets::SUB_CONTEST + 6].to_vec()
+    }
+
+    /// Get Pokemon's hidden ability slot (0 or 1), the top bit of the
+    /// IV/egg/ability word in the substructure block
+    #[wasm_bindgen(getter)]
+    pub fn ability_slot(&self) -> u8 {
+        let packed = read_u32_le(&self.decrypted(), PokemonOffsets::SUB_IV_EGG_ABILITY);
+        ((packed >> 31) & 0x1) as u8
+    }
+
+    /// Get Pokemon's gender, derived from its personality value and species gender ratio
+    #[wasm_bindgen(getter)]
+    pub fn gender(&self) -> Gender {
+        gender_for(self.species_id(), self.personality())
+    }
+
+    /// Get Pokemon's friendship (happiness) value, decrypted from the substructure block
+    #[wasm_bindgen(getter)]
+    pub fn friendship(&self) -> u8 {
+        self.decrypted()[PokemonOffsets::SUB_FRIENDSHIP]
+    }
+
+    /// Get Pokemon's Pokerus status byte, decrypted from the substructure block:
+    /// the low nibble counts days remaining infected, the high nibble is the strain
+    #[wasm_bindgen(getter)]
+    pub fn pokerus(&self) -> u8 {
+        self.decrypted()[PokemonOffsets::SUB_POKERUS]
+    }
+
+    /// Get the map index Pokemon was met/hatched on, decrypted from the substructure block
+    #[wasm_bindgen(getter)]
+    pub fn met_location(&self) -> u8 {
+        self.decrypted()[PokemonOffsets::SUB_MET_LOCATION]
+    }
+
+    /// Get Pokemon's packed origin info (met level, game of origin, Poke Ball,
+    /// OT gender), decrypted from the substructure block
+    #[wasm_bindgen(getter)]
+    pub fn origins_info(&self) -> u16 {
+        read_u16_le(&self.decrypted(), PokemonOffsets::SUB_ORIGINS_INFO)
+    }
+
+    /// Get Pokemon's level as derived from its total experience and species
+    /// growth rate, independent of the stored `level` byte
+    #[wasm_bindgen]
+    pub fn level_from_experience(&self) -> u8 {
+        level_for_experience(self.species_id(), self.experience())
+    }
+
+    /// Get the ×1.1/×0.9/×1.0 stat multipliers implied by this Pokemon's nature,
+    /// expressed as percentages (110/90/100) so front-ends can highlight
+    /// boosted/hindered stats without re-deriving the nature-to-stat mapping
+    #[wasm_bindgen]
+    pub fn nature_stat_modifiers(&self) -> PokemonStats {
+        let nature = self.nature();
+        PokemonStats::new(
+            100,
+            (get_nature_modifier(&nature, 1) * 100.0) as u16,
+            (get_nature_modifier(&nature, 2) * 100.0) as u16,
+            (get_nature_modifier(&nature, 3) * 100.0) as u16,
+            (get_nature_modifier(&nature, 4) * 100.0) as u16,
+            (get_nature_modifier(&nature, 5) * 100.0) as u16,
+        )
+    }
+
     /// Get all stats as a PokemonStats object
     #[wasm_bindgen]
     pub fn get_stats(&self) -> PokemonStats {
@@ -230,54 +414,57 @@ impl Pokemon {
         )
     }
     
-    /// Check if Pokemon data appears valid (has non-zero species ID)
+    /// Check if Pokemon data appears valid: enough bytes, the decrypted
+    /// substructure matches its stored checksum, and the species is non-zero
     #[wasm_bindgen]
     pub fn is_valid(&self) -> bool {
-        // For now, just check if we have enough data
-        // In a full implementation, we'd decrypt and check species ID
-        self.raw_bytes.len() >= 100 && self.personality() != 0
+        self.validate().is_ok()
     }
-    
+
+    /// Strictly validate this Pokemon's data, returning the specific
+    /// `PokemonError` instead of collapsing every failure into `false`
+    #[wasm_bindgen]
+    pub fn validate(&self) -> Result<(), JsError> {
+        if self.raw_bytes.len() < 100 {
+            return Err(PokemonError::TooShort.into());
+        }
+        if !verify_pokemon_checksum(&self.raw_bytes) {
+            return Err(PokemonError::BadChecksum.into());
+        }
+        if self.species_id() == 0 {
+            return Err(PokemonError::InvalidSpecies.into());
+        }
+        Ok(())
+    }
+
     /// Get a formatted string representation of the Pokemon
     #[wasm_bindgen]
-    pub fn to_string(&self) -> String {
-        format!(
+    pub fn to_string(&self) -> Result<String, JsError> {
+        Ok(format!(
             "Pokemon {{ nickname: {}, level: {}, hp: {}/{}, nature: {} }}",
-            self.nickname(),
-            self.level(),
+            self.nickname()?,
+            self.level()?,
             self.current_hp(),
             self.max_hp(),
             self.nature()
-        )
+        ))
     }
 }
 
 // Internal methods not exposed to JavaScript
 impl Pokemon {
-    /// Get species ID (would require decryption in full implementation)
-    pub(crate) fn species_id(&self) -> u16 {
-        // This is a simplified version - real implementation would decrypt the data
-        // For now, return a placeholder value
-        1 // Placeholder for Bulbasaur
+    /// Decrypt and de-shuffle the 48-byte substructure block, restoring it to
+    /// canonical Growth/Attacks/EVs/Misc order regardless of physical shuffling
+    fn decrypted(&self) -> [u8; 48] {
+        decrypt_pokemon(&self.raw_bytes)
     }
-    
-    /// Calculate stats based on base stats, IVs, EVs, and nature
-    pub(crate) fn calculate_total_stats(&self, base_stats: &[u16; 6]) -> [u16; 6] {
-        let level = self.level();
-        let nature = self.nature();
-        
-        // For now, use placeholder IV/EV values
-        // In full implementation, these would be extracted from encrypted data
-        let ivs = [31, 31, 31, 31, 31, 31]; // Perfect IVs as placeholder
-        let evs = [0, 0, 0, 0, 0, 0]; // No EVs as placeholder
-        
-        [
-            calculate_hp_stat(base_stats[0], ivs[0], evs[0], level),
-            calculate_stat(base_stats[1], ivs[1], evs[1], level, get_nature_modifier(&nature, 1)),
-            calculate_stat(base_stats[2], ivs[2], evs[2], level, get_nature_modifier(&nature, 2)),
-            calculate_stat(base_stats[3], ivs[3], evs[3], level, get_nature_modifier(&nature, 3)),
-            calculate_stat(base_stats[4], ivs[4], evs[4], level, get_nature_modifier(&nature, 4)),
-            calculate_stat(base_stats[5], ivs[5], evs[5], level, get_nature_modifier(&nature, 5)),
-        ]
+
+    /// Decrypt the substructure block, apply `mutate` to its canonical-order
+    /// bytes, then re-encrypt and write the result (and its checksum) back
+    /// into `raw_bytes`
+    fn set_decrypted(&mut self, mutate: impl FnOnce(&mut [u8; 48])) {
+        let mut decrypted = self.decrypted();
+        mutate(&mut decrypted);
+        encrypt_pokemon(&mut self.raw_bytes, &decrypted);
     }
 }
\ No newline at end of file
@@ -1,20 +1,37 @@
+use crate::error::{SaveError, SaveIntegrityReport, SectorStatus};
 use crate::pokemon::Pokemon;
+use crate::species::compute_stats;
 use crate::types::{
-    SaveData, PlayTimeData, SectorInfo, SaveLayout,
-    VANILLA_EMERALD_SIGNATURE, POKEMON_SIZE, SECTOR_SIZE, SECTOR_DATA_SIZE
+    SaveData, PlayTimeData, SectorInfo, SaveLayout, GameVersion, Language, PokemonStats,
+    VANILLA_EMERALD_SIGNATURE, POKEMON_SIZE, MAX_PARTY_SIZE, SECTOR_SIZE, SECTOR_DATA_SIZE
 };
 use crate::utils::{
-    bytes_to_gba_string, calculate_sector_checksum, read_u16_le, read_u32_le
+    bytes_to_gba_string, compute_sector_checksum, read_u16_le, read_u32_le, write_u16_le, write_u32_le
 };
 use wasm_bindgen::prelude::*;
-use wasm_bindgen::prelude::*;
 use std::collections::HashMap;
 
+// Sector footer layout (relative to the end of each 4096-byte sector)
+const FOOTER_SIZE: usize = 12;
+const FOOTER_CHECKSUM_OFFSET: usize = 2;
+const FOOTER_SIGNATURE_OFFSET: usize = 4;
+const FOOTER_COUNTER_OFFSET: usize = 8;
+
+/// Parses a full Gen3 save file (both 14-sector slots) into its component
+/// parts: `load_save_data` walks every sector's footer to validate its
+/// signature and checksum (`get_sector_info_internal`/`build_sector_map`),
+/// picks the active save block by comparing the two slots' save counters
+/// (`determine_active_slot`), and reassembles SaveBlock1/SaveBlock2 from
+/// whichever physical sectors the active slot's `sector_map` points at
+/// (`extract_saveblock1`/`extract_saveblock2`) to parse the party
+/// (`get_party_pokemon`), trainer info and play time.
 #[wasm_bindgen]
 pub struct SaveParser {
     save_data: Vec<u8>,
     active_slot_start: usize,
     sector_map: HashMap<u16, usize>,
+    modified_party: Option<Vec<Pokemon>>,
+    game_version: GameVersion,
 }
 
 #[wasm_bindgen]
@@ -26,22 +43,31 @@ impl SaveParser {
             save_data: Vec::new(),
             active_slot_start: 0,
             sector_map: HashMap::new(),
+            modified_party: None,
+            game_version: GameVersion::Emerald,
         }
     }
-    
+
     /// Load save data from bytes
     #[wasm_bindgen]
     pub fn load_save_data(&mut self, data: &[u8]) -> Result<(), JsError> {
-        if data.len() < 131072 { // 128KB minimum for Emerald save
-            return Err(JsError::new("Save file too small"));
+        if data.len() < 131072 { // 128KB minimum for a Gen3 save
+            return Err(SaveError::TooSmall.into());
         }
-        
+
         self.save_data = data.to_vec();
         self.determine_active_slot();
         self.build_sector_map();
-        
+        self.game_version = self.detect_game();
+
         Ok(())
     }
+
+    /// Get the detected game version for the loaded save
+    #[wasm_bindgen]
+    pub fn get_game_version(&self) -> GameVersion {
+        self.game_version
+    }
     
     /// Parse the complete save data and return SaveData
     #[wasm_bindgen]
@@ -50,18 +76,17 @@ impl SaveParser {
             return Err(JsError::new("No save data loaded"));
         }
         
-        let _saveblock1_data = self.extract_saveblock1()?;
         let saveblock2_data = self.extract_saveblock2()?;
-        
+
         let player_name = self.parse_player_name(&saveblock2_data);
         let play_time = self.parse_play_time(&saveblock2_data);
-        // Note: In full implementation, party_pokemon would be stored in SaveData
-        // For now, we'll access them separately via get_party_pokemon()
-        
+        // Party Pokemon aren't part of SaveData; fetch them separately via get_party_pokemon()
+
         Ok(SaveData::new(
             player_name,
             (self.active_slot_start / 14) as u8, // Convert to slot number
             play_time,
+            self.game_version,
         ))
     }
     
@@ -75,7 +100,22 @@ impl SaveParser {
         let saveblock1_data = self.extract_saveblock1()?;
         self.parse_party_pokemon(&saveblock1_data)
     }
-    
+
+    /// Get recalculated stats for each party Pokemon, derived from their
+    /// decrypted species/IVs/EVs/nature rather than the stored stat fields.
+    /// Diffing these against the stored stats surfaces tampered/hacked Pokemon.
+    ///
+    /// `species::BASE_STATS` only covers 14 of ~386 Gen3 species, so this
+    /// returns `Err` (a `PokemonError::UnknownSpecies`) for any party member
+    /// outside that table — which, for a real save, is most of them. An `Err`
+    /// here means "can't verify this Pokemon's stats", not "this Pokemon is
+    /// hacked"; callers must not treat it as a tamper signal until the table
+    /// is extended to cover the species actually in use.
+    #[wasm_bindgen]
+    pub fn get_party_stats(&self) -> Result<Vec<PokemonStats>, JsError> {
+        self.get_party_pokemon()?.iter().map(compute_stats).collect()
+    }
+
     /// Get player name from save data
     #[wasm_bindgen]
     pub fn get_player_name(&self) -> Result<String, JsError> {
@@ -107,10 +147,154 @@ impl SaveParser {
     pub fn get_valid_sector_count(&self) -> usize {
         self.sector_map.len()
     }
+
+    /// Stage a replacement party to be written out by `export_save_data`
+    #[wasm_bindgen]
+    pub fn set_party_pokemon(&mut self, party: Vec<Pokemon>) -> Result<(), JsError> {
+        if party.len() > MAX_PARTY_SIZE {
+            return Err(JsError::new(&format!(
+                "Party cannot exceed {} Pokemon, got {}",
+                MAX_PARTY_SIZE,
+                party.len()
+            )));
+        }
+
+        self.modified_party = Some(party);
+        Ok(())
+    }
+
+    /// Walk all 32 sectors of both save slots and report per-sector
+    /// validity/counter/checksum status, without failing on a corrupt save.
+    /// Lets callers warn about corruption and offer a fallback to the other
+    /// slot instead of failing opaquely.
+    #[wasm_bindgen]
+    pub fn validate(&self) -> SaveIntegrityReport {
+        let sectors: Vec<SectorStatus> = (0..32)
+            .map(|i| {
+                let info = self.get_sector_info_internal(i);
+                let issue = self.diagnose_sector(i).err().map(|e| e.to_string());
+                SectorStatus::new(i as u8, info.id(), info.valid(), info.counter(), issue)
+            })
+            .collect();
+
+        let slot1_valid_sectors = sectors[0..14].iter().filter(|s| s.valid()).count() as u8;
+        let slot2_valid_sectors = sectors[14..32].iter().filter(|s| s.valid()).count() as u8;
+
+        let active_slot = self.get_active_slot();
+        let inactive_sectors = if active_slot == 1 { &sectors[14..32] } else { &sectors[0..14] };
+        // A slot is worth recovering from only if it holds valid copies of
+        // SaveBlock2 (id 0) and every SaveBlock1 sector of the detected layout.
+        let has_valid_id = |id: u16| inactive_sectors.iter().any(|s| s.valid() && s.id() == id);
+        let inactive_slot_recoverable = (0..=self.layout().saveblock1_sector_count as u16).all(has_valid_id);
+
+        SaveIntegrityReport::new(active_slot, slot1_valid_sectors, slot2_valid_sectors, inactive_slot_recoverable, sectors)
+    }
+
+    /// Recompute and rewrite the checksum footer for every sector in the active
+    /// slot, against the currently loaded save data. Call this after editing
+    /// `get_sector_info`-visible data directly so the game accepts the save.
+    #[wasm_bindgen]
+    pub fn recalculate_checksums(&mut self) {
+        let mut save_data = std::mem::take(&mut self.save_data);
+        self.recalculate_active_slot_checksums(&mut save_data);
+        self.save_data = save_data;
+    }
+
+    /// Run a Rune script once per party Pokemon, giving it read/write access
+    /// to level, EVs, IVs, nature, moves and held item, then stage the result
+    /// the same way `set_party_pokemon` does. Requires the `scripting` feature.
+    #[cfg(feature = "scripting")]
+    #[wasm_bindgen]
+    pub fn run_script(&mut self, src: &str) -> Result<(), JsError> {
+        let mut party = match self.modified_party.take() {
+            Some(party) => party,
+            None => self.get_party_pokemon()?,
+        };
+        crate::scripting::run_script_over_party(src, &mut party)?;
+        self.set_party_pokemon(party)
+    }
+
+    /// Reconstruct a full save buffer with any staged edits applied, ready to be
+    /// written back to a `.sav` file. Splices the edited SaveBlock1/SaveBlock2 data
+    /// into their physical sectors, recomputes every touched sector checksum, and
+    /// bumps the active slot's save counter so the game boots the edited slot.
+    #[wasm_bindgen]
+    pub fn export_save_data(&self) -> Result<Vec<u8>, JsError> {
+        if self.save_data.is_empty() {
+            return Err(JsError::new("No save data loaded"));
+        }
+
+        let mut save_data = self.save_data.clone();
+
+        let mut saveblock1_data = self.extract_saveblock1()?;
+        if let Some(party) = &self.modified_party {
+            self.write_party_pokemon(&mut saveblock1_data, party)?;
+        }
+        let saveblock2_data = self.extract_saveblock2()?;
+
+        let saveblock1_sector_count = self.layout().saveblock1_sector_count as u16;
+        for sector_id in 1..=saveblock1_sector_count {
+            let chunk_offset = ((sector_id - 1) as usize) * SECTOR_DATA_SIZE;
+            let chunk = &saveblock1_data[chunk_offset..chunk_offset + SECTOR_DATA_SIZE];
+            self.write_sector_data(&mut save_data, sector_id, chunk)?;
+        }
+        self.write_sector_data(&mut save_data, 0, &saveblock2_data)?;
+
+        self.recalculate_active_slot_checksums(&mut save_data);
+        self.bump_save_counter(&mut save_data)?;
+
+        Ok(save_data)
+    }
 }
 
 // Internal implementation methods
 impl SaveParser {
+    /// The SaveBlock1 layout for the detected game version
+    fn layout(&self) -> SaveLayout {
+        SaveLayout::for_version(self.game_version)
+    }
+
+    /// Classify the loaded save as Ruby/Sapphire, FireRed/LeafGreen, or Emerald.
+    ///
+    /// FireRed/LeafGreen redesigned SaveBlock1 and moved the party offset much
+    /// earlier in the struct. A sane party count at that offset isn't enough
+    /// proof by itself — that offset in an Emerald/RS-shaped SaveBlock1 is just
+    /// an arbitrary early field, with real odds of coincidentally holding a
+    /// value in 1-6 — so this also requires the Pokemon the FRLG layout derives
+    /// from that count to actually pass `Pokemon::validate()` (checksum and
+    /// species both check out), which a coincidental match won't. Ruby/Sapphire
+    /// and Emerald share the same early-field offsets and only differ in how
+    /// many sectors SaveBlock1 spans (3 vs 4), so once FRLG is ruled out this
+    /// falls back to checking whether logical sector ID 4 (the fourth
+    /// SaveBlock1 sector, only present in Emerald) was found by
+    /// `build_sector_map` in the active slot. Callers who already know which
+    /// game they're working with can use `SaveLayout::for_version` directly
+    /// instead of relying on detection.
+    fn detect_game(&self) -> GameVersion {
+        let frlg_layout = SaveLayout::for_version(GameVersion::FireRedLeafGreen);
+        if let Ok(saveblock1) = self.extract_saveblock1_with_layout(&frlg_layout) {
+            if let Some(&count) = saveblock1.get(frlg_layout.party_count_offset) {
+                if count > 0 && count as usize <= MAX_PARTY_SIZE {
+                    let offset = frlg_layout.party_offset;
+                    let first_slot_is_valid = saveblock1
+                        .get(offset..offset + POKEMON_SIZE)
+                        .and_then(|bytes| Pokemon::from_bytes(bytes).ok())
+                        .is_some_and(|pokemon| pokemon.is_valid());
+
+                    if first_slot_is_valid {
+                        return GameVersion::FireRedLeafGreen;
+                    }
+                }
+            }
+        }
+
+        if self.sector_map.contains_key(&4) {
+            GameVersion::Emerald
+        } else {
+            GameVersion::RubySapphire
+        }
+    }
+
     /// Determine which save slot is active based on sector counters
     fn determine_active_slot(&mut self) {
         let slot1_counter_sum = self.get_counter_sum(&(0..14).collect::<Vec<_>>());
@@ -173,27 +357,59 @@ impl SaveParser {
         // Verify checksum
         let sector_start = sector_index * SECTOR_SIZE;
         let sector_data = &self.save_data[sector_start..sector_start + SECTOR_DATA_SIZE];
-        let calculated_checksum = calculate_sector_checksum(sector_data);
+        let calculated_checksum = compute_sector_checksum(sector_data, SECTOR_DATA_SIZE);
         let valid = calculated_checksum == checksum;
         
         SectorInfo::new(sector_id, checksum, counter, valid)
     }
-    
-    /// Extract SaveBlock1 data from sectors 1-4
+
+    /// Diagnose why a sector failed validation, mirroring `get_sector_info_internal`
+    /// but returning a structured `SaveError` instead of silently marking it invalid
+    fn diagnose_sector(&self, sector_index: usize) -> Result<(), SaveError> {
+        let footer_offset = (sector_index * SECTOR_SIZE) + SECTOR_SIZE - FOOTER_SIZE;
+        if footer_offset + FOOTER_SIZE > self.save_data.len() {
+            return Err(SaveError::TruncatedSector);
+        }
+
+        let signature = read_u32_le(&self.save_data, footer_offset + FOOTER_SIGNATURE_OFFSET);
+        if signature != VANILLA_EMERALD_SIGNATURE {
+            return Err(SaveError::BadSignature);
+        }
+
+        let sector_id = read_u16_le(&self.save_data, footer_offset);
+        let checksum = read_u16_le(&self.save_data, footer_offset + FOOTER_CHECKSUM_OFFSET);
+        let sector_start = sector_index * SECTOR_SIZE;
+        let sector_data = &self.save_data[sector_start..sector_start + SECTOR_DATA_SIZE];
+        let calculated_checksum = compute_sector_checksum(sector_data, SECTOR_DATA_SIZE);
+
+        if calculated_checksum != checksum {
+            return Err(SaveError::ChecksumMismatch { sector: sector_id, expected: checksum, got: calculated_checksum });
+        }
+
+        Ok(())
+    }
+
+    /// Extract SaveBlock1 data from the sectors the detected game version stores it in
     fn extract_saveblock1(&self) -> Result<Vec<u8>, JsError> {
-        let mut saveblock1_data = vec![0u8; SECTOR_DATA_SIZE * 4]; // 4 sectors
-        
-        for sector_id in 1..=4 {
+        self.extract_saveblock1_with_layout(&self.layout())
+    }
+
+    /// Extract SaveBlock1 data assuming a specific layout, without depending on
+    /// `self.game_version` (used by `detect_game` to probe candidate layouts)
+    fn extract_saveblock1_with_layout(&self, layout: &SaveLayout) -> Result<Vec<u8>, JsError> {
+        let mut saveblock1_data = vec![0u8; SECTOR_DATA_SIZE * layout.saveblock1_sector_count];
+
+        for sector_id in 1..=layout.saveblock1_sector_count as u16 {
             if let Some(&sector_idx) = self.sector_map.get(&sector_id) {
                 let start_offset = sector_idx * SECTOR_SIZE;
                 let sector_data = &self.save_data[start_offset..start_offset + SECTOR_DATA_SIZE];
                 let chunk_offset = ((sector_id - 1) * SECTOR_DATA_SIZE as u16) as usize;
-                
+
                 saveblock1_data[chunk_offset..chunk_offset + SECTOR_DATA_SIZE]
                     .copy_from_slice(sector_data);
             }
         }
-        
+
         Ok(saveblock1_data)
     }
     
@@ -203,60 +419,34 @@ impl SaveParser {
             let start_offset = sector_idx * SECTOR_SIZE;
             Ok(self.save_data[start_offset..start_offset + SECTOR_DATA_SIZE].to_vec())
         } else {
-            Err(JsError::new("SaveBlock2 sector (ID 0) not found"))
+            Err(SaveError::MissingSector(0).into())
         }
     }
     
     /// Parse party Pokemon from SaveBlock1 data
     fn parse_party_pokemon(&self, saveblock1_data: &[u8]) -> Result<Vec<Pokemon>, JsError> {
+        let layout = self.layout();
         let mut party_pokemon = Vec::new();
-        
+
         // Get party count
-        if SaveLayout::PARTY_COUNT_OFFSET >= saveblock1_data.len() {
+        if layout.party_count_offset >= saveblock1_data.len() {
             return Ok(party_pokemon);
         }
-        
-        let party_count = saveblock1_data[SaveLayout::PARTY_COUNT_OFFSET];
-        
-        // Debug: Log the actual bytes we're reading
-        if saveblock1_data.len() > SaveLayout::PARTY_COUNT_OFFSET + 10 {
-            let context_start = SaveLayout::PARTY_COUNT_OFFSET.saturating_sub(5);
-            let context_end = (SaveLayout::PARTY_COUNT_OFFSET + 10).min(saveblock1_data.len());
-            let context_bytes: Vec<String> = saveblock1_data[context_start..context_end]
-                .iter()
-                .enumerate()
-                .map(|(i, b)| {
-                    let offset = context_start + i;
-                    if offset == SaveLayout::PARTY_COUNT_OFFSET {
-                        format!("[{:02x}]", b) // Mark the target byte
-                    } else {
-                        format!("{:02x}", b)
-                    }
-                })
-                .collect();
-            crate::console_log!(
-                "Party count at offset 0x{:x} in SaveBlock1: {} (context: {})",
-                SaveLayout::PARTY_COUNT_OFFSET,
-                party_count,
-                context_bytes.join(" ")
-            );
-        }
-        
-        let max_party_size = 12; // Increase limit to handle different game variants
-        
-        if party_count > max_party_size {
+
+        let party_count = saveblock1_data[layout.party_count_offset];
+
+        if party_count as usize > MAX_PARTY_SIZE {
             return Err(JsError::new(&format!("Invalid party count: {}", party_count)));
         }
-        
-        // Also check if party_count seems reasonable
+
         if party_count == 0 {
             return Ok(party_pokemon); // No Pokemon in party
         }
-        
+
         // Parse each Pokemon in the party
         for slot in 0..party_count as usize {
-            let offset = SaveLayout::PARTY_OFFSET + slot * POKEMON_SIZE;
-            
+            let offset = layout.party_offset + slot * POKEMON_SIZE;
+
             if offset + POKEMON_SIZE > saveblock1_data.len() {
                 break;
             }
@@ -285,7 +475,7 @@ impl SaveParser {
         }
         
         let player_name_bytes = &saveblock2_data[0..8];
-        let name = bytes_to_gba_string(player_name_bytes);
+        let name = bytes_to_gba_string(player_name_bytes, Language::Western);
         
         if name.is_empty() {
             "Unknown".to_string()
@@ -296,22 +486,226 @@ impl SaveParser {
     
     /// Parse play time from SaveBlock2 data
     fn parse_play_time(&self, saveblock2_data: &[u8]) -> PlayTimeData {
-        if saveblock2_data.len() < SaveLayout::PLAY_TIME_SECONDS + 1 {
+        let layout = self.layout();
+        if saveblock2_data.len() < layout.play_time_seconds + 1 {
             return PlayTimeData::new(0, 0, 0);
         }
-        
-        let hours = read_u16_le(saveblock2_data, SaveLayout::PLAY_TIME_HOURS);
-        let minutes = if SaveLayout::PLAY_TIME_MINUTES < saveblock2_data.len() {
-            saveblock2_data[SaveLayout::PLAY_TIME_MINUTES]
+
+        let hours = read_u16_le(saveblock2_data, layout.play_time_hours);
+        let minutes = if layout.play_time_minutes < saveblock2_data.len() {
+            saveblock2_data[layout.play_time_minutes]
         } else {
             0
         };
-        let seconds = if SaveLayout::PLAY_TIME_SECONDS < saveblock2_data.len() {
-            saveblock2_data[SaveLayout::PLAY_TIME_SECONDS]
+        let seconds = if layout.play_time_seconds < saveblock2_data.len() {
+            saveblock2_data[layout.play_time_seconds]
         } else {
             0
         };
-        
+
         PlayTimeData::new(hours, minutes, seconds)
     }
+
+    /// Write the staged party data (count + raw Pokemon bytes) into a SaveBlock1 buffer
+    fn write_party_pokemon(&self, saveblock1_data: &mut [u8], party: &[Pokemon]) -> Result<(), JsError> {
+        let layout = self.layout();
+        if layout.party_count_offset >= saveblock1_data.len() {
+            return Err(JsError::new("SaveBlock1 buffer too small for party count"));
+        }
+        saveblock1_data[layout.party_count_offset] = party.len() as u8;
+
+        for (slot, pokemon) in party.iter().enumerate() {
+            let offset = layout.party_offset + slot * POKEMON_SIZE;
+            if offset + POKEMON_SIZE > saveblock1_data.len() {
+                return Err(JsError::new("SaveBlock1 buffer too small for party data"));
+            }
+            saveblock1_data[offset..offset + POKEMON_SIZE].copy_from_slice(&pokemon.get_raw_bytes());
+        }
+
+        Ok(())
+    }
+
+    /// Copy a logical sector's data into its physical sector within the active slot
+    fn write_sector_data(&self, save_data: &mut [u8], sector_id: u16, chunk: &[u8]) -> Result<(), JsError> {
+        let &sector_idx = self.sector_map.get(&sector_id)
+            .ok_or_else(|| JsError::new(&format!("Sector {} not found in active slot", sector_id)))?;
+
+        let start = sector_idx * SECTOR_SIZE;
+        save_data[start..start + SECTOR_DATA_SIZE].copy_from_slice(chunk);
+        Ok(())
+    }
+
+    /// Recompute and rewrite the checksum footer for every sector in the active slot
+    fn recalculate_active_slot_checksums(&self, save_data: &mut [u8]) {
+        for &sector_idx in self.sector_map.values() {
+            let sector_start = sector_idx * SECTOR_SIZE;
+            let sector_data = &save_data[sector_start..sector_start + SECTOR_DATA_SIZE];
+            let checksum = compute_sector_checksum(sector_data, SECTOR_DATA_SIZE);
+
+            let footer_offset = sector_start + SECTOR_SIZE - FOOTER_SIZE;
+            write_u16_le(save_data, footer_offset + FOOTER_CHECKSUM_OFFSET, checksum);
+        }
+    }
+
+    /// Bump the active slot's save counter above the inactive slot's, so the game
+    /// boots the edited slot on next load
+    fn bump_save_counter(&self, save_data: &mut [u8]) -> Result<(), JsError> {
+        let inactive_slot_start = if self.active_slot_start == 0 { 14 } else { 0 };
+        let inactive_counter = (inactive_slot_start..inactive_slot_start + 14)
+            .map(|i| self.get_sector_info_internal(i))
+            .filter(|info| info.valid())
+            .map(|info| info.counter())
+            .max()
+            .unwrap_or(0);
+
+        let new_counter = inactive_counter.wrapping_add(1);
+
+        let active_range = self.active_slot_start..self.active_slot_start + 14;
+        for sector_idx in active_range {
+            let footer_offset = (sector_idx * SECTOR_SIZE) + SECTOR_SIZE - FOOTER_SIZE;
+            let signature = read_u32_le(save_data, footer_offset + FOOTER_SIGNATURE_OFFSET);
+            if signature != VANILLA_EMERALD_SIGNATURE {
+                continue;
+            }
+            write_u32_le(save_data, footer_offset + FOOTER_COUNTER_OFFSET, new_counter);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PokemonOffsets;
+    use crate::utils::encrypt_pokemon;
+
+    /// Build a minimal valid 100-byte Pokemon: personality/OT ID left at 0
+    /// (so the substructure key is 0 and the shuffle order is identity),
+    /// with `species` and `level` set directly.
+    fn make_raw_pokemon(species: u16, level: u8) -> Vec<u8> {
+        let mut raw = vec![0u8; POKEMON_SIZE];
+        raw[PokemonOffsets::LEVEL] = level;
+
+        let mut ordered = [0u8; 48];
+        write_u16_le(&mut ordered, PokemonOffsets::SUB_SPECIES, species);
+        encrypt_pokemon(&mut raw, &ordered);
+
+        raw
+    }
+
+    /// Recompute and write a sector's footer (id, checksum, signature, counter)
+    /// from its already-written data, mirroring `recalculate_active_slot_checksums`.
+    fn write_sector_footer(save_data: &mut [u8], sector_index: usize, id: u16, counter: u32) {
+        let sector_start = sector_index * SECTOR_SIZE;
+        let checksum = compute_sector_checksum(&save_data[sector_start..sector_start + SECTOR_DATA_SIZE], SECTOR_DATA_SIZE);
+
+        let footer_offset = sector_start + SECTOR_SIZE - FOOTER_SIZE;
+        write_u16_le(save_data, footer_offset, id);
+        write_u16_le(save_data, footer_offset + FOOTER_CHECKSUM_OFFSET, checksum);
+        write_u32_le(save_data, footer_offset + FOOTER_SIGNATURE_OFFSET, VANILLA_EMERALD_SIGNATURE);
+        write_u32_le(save_data, footer_offset + FOOTER_COUNTER_OFFSET, counter);
+    }
+
+    /// Build a minimal two-slot Emerald save: slot 1 holds valid SaveBlock2
+    /// (sector 0) and SaveBlock1 (sectors 1-4, one of each a real Emerald
+    /// save spans) sectors with a single party Pokemon; slot 2 is left
+    /// entirely zeroed/invalid so slot 1 is unambiguously active.
+    fn synthetic_save(species: u16, level: u8) -> Vec<u8> {
+        let mut save_data = vec![0u8; 32 * SECTOR_SIZE];
+
+        let layout = SaveLayout::for_version(GameVersion::Emerald);
+        let saveblock1_sector0_start = SECTOR_SIZE; // physical sector 1
+        save_data[saveblock1_sector0_start + layout.party_count_offset] = 1;
+
+        let pokemon_offset = saveblock1_sector0_start + layout.party_offset;
+        save_data[pokemon_offset..pokemon_offset + POKEMON_SIZE]
+            .copy_from_slice(&make_raw_pokemon(species, level));
+
+        for (sector_index, id) in [(0usize, 0u16), (1, 1), (2, 2), (3, 3), (4, 4)] {
+            write_sector_footer(&mut save_data, sector_index, id, 5);
+        }
+
+        save_data
+    }
+
+    #[test]
+    fn export_then_reload_round_trips_edited_party() {
+        let mut parser = SaveParser::new();
+        parser.load_save_data(&synthetic_save(1, 5)).unwrap();
+        assert_eq!(parser.get_active_slot(), 1);
+        assert_eq!(parser.get_game_version(), GameVersion::Emerald);
+        assert_eq!(parser.get_valid_sector_count(), 5);
+
+        let mut party = parser.get_party_pokemon().unwrap();
+        assert_eq!(party.len(), 1);
+        assert_eq!(party[0].species_id(), 1);
+        assert_eq!(party[0].level().unwrap(), 5);
+
+        party[0].set_level(50);
+        parser.set_party_pokemon(party).unwrap();
+
+        let exported = parser.export_save_data().unwrap();
+
+        let mut reloaded = SaveParser::new();
+        reloaded.load_save_data(&exported).unwrap();
+
+        // Same slot, same sectors, and each of them re-validates cleanly
+        // against its recomputed checksum.
+        assert_eq!(reloaded.get_active_slot(), 1);
+        assert_eq!(reloaded.get_valid_sector_count(), 5);
+        for sector_index in 0..5 {
+            assert!(reloaded.get_sector_info(sector_index).valid());
+        }
+
+        // The active slot's counter was bumped past the (invalid, so zero)
+        // inactive slot's, which is what makes the game boot this slot.
+        assert!(reloaded.get_sector_info(0).counter() > 0);
+
+        let reloaded_party = reloaded.get_party_pokemon().unwrap();
+        assert_eq!(reloaded_party.len(), 1);
+        assert_eq!(reloaded_party[0].species_id(), 1);
+        assert_eq!(reloaded_party[0].level().unwrap(), 50);
+    }
+
+    #[test]
+    fn validate_flags_a_corrupted_sector_and_inactive_slot_recoverability() {
+        let mut save_data = synthetic_save(1, 5);
+
+        // Corrupt sector 2's checksum footer only, leaving its data untouched,
+        // so `validate` must catch the mismatch via `diagnose_sector`.
+        let footer_offset = 2 * SECTOR_SIZE + SECTOR_SIZE - FOOTER_SIZE;
+        write_u16_le(&mut save_data, footer_offset + FOOTER_CHECKSUM_OFFSET, 0xBEEF);
+
+        let mut parser = SaveParser::new();
+        parser.load_save_data(&save_data).unwrap();
+
+        let report = parser.validate();
+        assert_eq!(report.active_slot(), 1);
+        assert_eq!(report.slot1_valid_sectors(), 4); // 5 sectors minus the corrupted one
+        assert_eq!(report.slot2_valid_sectors(), 0);
+        assert!(!report.inactive_slot_recoverable()); // slot 2 has no valid sectors at all
+
+        let sectors = report.sectors();
+        assert!(sectors[0].valid());
+        assert!(!sectors[2].valid());
+        assert!(sectors[2].issue().unwrap().contains("checksum"));
+    }
+
+    #[test]
+    fn recalculate_checksums_repairs_a_corrupted_footer_in_place() {
+        let mut parser = SaveParser::new();
+        parser.load_save_data(&synthetic_save(1, 5)).unwrap();
+
+        // Directly corrupt sector 0's stored checksum without touching its
+        // data, simulating a caller who edited sector bytes by hand and
+        // forgot to keep the footer in sync.
+        let footer_offset = SECTOR_SIZE - FOOTER_SIZE;
+        write_u16_le(&mut parser.save_data, footer_offset + FOOTER_CHECKSUM_OFFSET, 0xBEEF);
+        assert!(!parser.get_sector_info(0).valid());
+
+        parser.recalculate_checksums();
+
+        assert!(parser.get_sector_info(0).valid());
+    }
 }
\ No newline at end of file
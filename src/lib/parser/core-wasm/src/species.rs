@@ -0,0 +1,144 @@
+use crate::error::PokemonError;
+use crate::pokemon::Pokemon;
+use crate::types::{Gender, PokemonStats};
+use crate::utils::{calculate_hp_stat, calculate_stat, get_nature_modifier};
+use wasm_bindgen::prelude::*;
+
+/// Base stats (HP, Attack, Defense, Speed, Sp. Attack, Sp. Defense) by National
+/// Dex number.
+///
+/// INCOMPLETE: only covers the Gen 1 starter lines, Pikachu/Raichu and
+/// Magikarp/Gyarados/Eevee (14 of ~386 Gen3 species) — extend with more
+/// entries as needed. `compute_stats` relies on this table to distinguish
+/// legitimate from tampered Pokemon, so a missing entry must surface as
+/// `PokemonError::UnknownSpecies`, never a guessed base-stat row.
+const BASE_STATS: &[(u16, [u16; 6])] = &[
+    (1, [45, 49, 49, 45, 65, 65]),    // Bulbasaur
+    (2, [60, 62, 63, 60, 80, 80]),    // Ivysaur
+    (3, [80, 82, 83, 80, 100, 100]),  // Venusaur
+    (4, [39, 52, 43, 65, 60, 50]),    // Charmander
+    (5, [58, 64, 58, 80, 80, 65]),    // Charmeleon
+    (6, [78, 84, 78, 100, 109, 85]),  // Charizard
+    (7, [44, 48, 65, 43, 50, 64]),    // Squirtle
+    (8, [59, 63, 80, 58, 65, 80]),    // Wartortle
+    (9, [79, 83, 100, 78, 85, 105]),  // Blastoise
+    (25, [35, 55, 40, 90, 50, 50]),   // Pikachu
+    (26, [60, 90, 55, 110, 90, 80]),  // Raichu
+    (129, [20, 10, 55, 80, 15, 20]),  // Magikarp
+    (130, [95, 125, 79, 81, 60, 100]), // Gyarados
+    (133, [55, 55, 50, 55, 45, 65]),  // Eevee
+];
+
+/// Look up base stats for a species by National Dex number
+pub fn base_stats_for_species(species_id: u16) -> Option<[u16; 6]> {
+    BASE_STATS.iter().find(|(id, _)| *id == species_id).map(|(_, stats)| *stats)
+}
+
+/// Gender ratio threshold by National Dex number: the personality value's low
+/// byte is compared against this threshold (`< threshold` is female), except
+/// for the three special-cased constants below. Species not in this table
+/// default to 127 (the standard 50/50 split shared by most Pokemon).
+const GENDER_RATIO: &[(u16, u8)] = &[
+    (1, 31), (2, 31), (3, 31),    // Bulbasaur line: 87.5% male
+    (4, 31), (5, 31), (6, 31),    // Charmander line: 87.5% male
+    (7, 31), (8, 31), (9, 31),    // Squirtle line: 87.5% male
+];
+
+/// `GENDER_RATIO` threshold meaning the species has no gender
+pub const GENDERLESS: u8 = 0xFF;
+/// `GENDER_RATIO` threshold meaning the species is always female
+pub const ALWAYS_FEMALE: u8 = 0xFE;
+/// `GENDER_RATIO` threshold meaning the species is always male
+pub const ALWAYS_MALE: u8 = 0x00;
+
+/// Look up the gender ratio threshold for a species, defaulting to the
+/// standard 50/50 split (threshold 127) for species not in `GENDER_RATIO`
+fn gender_ratio_for_species(species_id: u16) -> u8 {
+    GENDER_RATIO.iter().find(|(id, _)| *id == species_id).map(|(_, ratio)| *ratio).unwrap_or(127)
+}
+
+/// Determine gender from personality value and species, following Gen3's rule:
+/// the personality's low byte compared against the species' gender ratio threshold
+pub fn gender_for(species_id: u16, personality: u32) -> Gender {
+    let ratio = gender_ratio_for_species(species_id);
+    match ratio {
+        GENDERLESS => Gender::Genderless,
+        ALWAYS_FEMALE => Gender::Female,
+        ALWAYS_MALE => Gender::Male,
+        threshold => {
+            if (personality & 0xFF) as u8 >= threshold {
+                Gender::Male
+            } else {
+                Gender::Female
+            }
+        }
+    }
+}
+
+/// Gen3 experience growth rate curve. Determines how much experience a
+/// species needs to reach a given level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GrowthRate {
+    Fast,
+    MediumFast,
+    MediumSlow,
+    Slow,
+}
+
+/// Total experience required to reach `level` under a given growth rate
+fn experience_for_level(rate: GrowthRate, level: u32) -> u32 {
+    let n = level as f64;
+    let exp = match rate {
+        GrowthRate::Fast => 4.0 * n.powi(3) / 5.0,
+        GrowthRate::MediumFast => n.powi(3),
+        GrowthRate::MediumSlow => 6.0 * n.powi(3) / 5.0 - 15.0 * n.powi(2) + 100.0 * n - 140.0,
+        GrowthRate::Slow => 5.0 * n.powi(3) / 4.0,
+    };
+    exp.max(0.0) as u32
+}
+
+/// Growth rate by National Dex number. Species not in this table default to
+/// Medium Fast, the most common growth rate.
+const GROWTH_RATES: &[(u16, GrowthRate)] = &[
+    (1, GrowthRate::MediumSlow), (2, GrowthRate::MediumSlow), (3, GrowthRate::MediumSlow),
+    (4, GrowthRate::MediumSlow), (5, GrowthRate::MediumSlow), (6, GrowthRate::MediumSlow),
+    (7, GrowthRate::MediumSlow), (8, GrowthRate::MediumSlow), (9, GrowthRate::MediumSlow),
+    (25, GrowthRate::MediumFast), (26, GrowthRate::MediumFast),
+    (129, GrowthRate::Slow), (130, GrowthRate::Slow),
+    (133, GrowthRate::MediumFast),
+];
+
+fn growth_rate_for_species(species_id: u16) -> GrowthRate {
+    GROWTH_RATES.iter().find(|(id, _)| *id == species_id).map(|(_, rate)| *rate).unwrap_or(GrowthRate::MediumFast)
+}
+
+/// Derive a Pokemon's level from its total experience and species growth rate,
+/// by finding the highest level (1-100) whose experience threshold it has met.
+pub fn level_for_experience(species_id: u16, experience: u32) -> u8 {
+    let rate = growth_rate_for_species(species_id);
+    (1..=100u8)
+        .rev()
+        .find(|&level| experience_for_level(rate, level as u32) <= experience)
+        .unwrap_or(1)
+}
+
+/// Recalculate a Pokemon's six stats from its decrypted species, IVs, EVs and
+/// nature, instead of trusting the (possibly tampered) stored stat fields.
+pub fn compute_stats(pokemon: &Pokemon) -> Result<PokemonStats, JsError> {
+    let species_id = pokemon.species_id();
+    let evs = pokemon.evs();
+    let ivs = pokemon.ivs();
+
+    let base_stats = base_stats_for_species(species_id).ok_or(PokemonError::UnknownSpecies(species_id))?;
+    let level = pokemon.level()?;
+    let nature = pokemon.nature();
+
+    Ok(PokemonStats::new(
+        calculate_hp_stat(base_stats[0], ivs[0], evs[0], level),
+        calculate_stat(base_stats[1], ivs[1], evs[1], level, get_nature_modifier(&nature, 1)),
+        calculate_stat(base_stats[2], ivs[2], evs[2], level, get_nature_modifier(&nature, 2)),
+        calculate_stat(base_stats[3], ivs[3], evs[3], level, get_nature_modifier(&nature, 3)),
+        calculate_stat(base_stats[4], ivs[4], evs[4], level, get_nature_modifier(&nature, 4)),
+        calculate_stat(base_stats[5], ivs[5], evs[5], level, get_nature_modifier(&nature, 5)),
+    ))
+}
@@ -71,23 +71,27 @@ pub struct SaveData {
     player_name: String,
     active_slot: u8,
     play_time: PlayTimeData,
+    game_version: GameVersion,
 }
 
 #[wasm_bindgen]
 impl SaveData {
     #[wasm_bindgen(constructor)]
-    pub fn new(player_name: String, active_slot: u8, play_time: PlayTimeData) -> SaveData {
-        SaveData { player_name, active_slot, play_time }
+    pub fn new(player_name: String, active_slot: u8, play_time: PlayTimeData, game_version: GameVersion) -> SaveData {
+        SaveData { player_name, active_slot, play_time, game_version }
     }
 
     #[wasm_bindgen(getter)]
     pub fn player_name(&self) -> String { self.player_name.clone() }
-    
+
     #[wasm_bindgen(getter)]
     pub fn active_slot(&self) -> u8 { self.active_slot }
-    
+
     #[wasm_bindgen(getter)]
     pub fn play_time(&self) -> PlayTimeData { self.play_time.clone() }
+
+    #[wasm_bindgen(getter)]
+    pub fn game_version(&self) -> GameVersion { self.game_version }
 }
 
 // Pokemon Emerald constants
@@ -115,17 +119,100 @@ impl PokemonOffsets {
     pub const SP_DEFENSE: usize = 0x62;
     pub const STATUS: usize = 0x50;
     pub const LEVEL: usize = 0x54;
-    pub const SPECIES_ID: usize = 0x20; // Encrypted section, will need decryption
+
+    // Offsets within the 48-byte decrypted substructure block (always ordered
+    // Growth, Attacks, EVs, Misc regardless of how they were physically shuffled)
+    pub const SUB_SPECIES: usize = 0x00;          // Growth +0x00 (u16)
+    pub const SUB_HELD_ITEM: usize = 0x02;        // Growth +0x02 (u16)
+    pub const SUB_EXPERIENCE: usize = 0x04;       // Growth +0x04 (u32)
+    pub const SUB_PP_BONUSES: usize = 0x08;       // Growth +0x08 (u8)
+    pub const SUB_FRIENDSHIP: usize = 0x09;       // Growth +0x09 (u8)
+
+    pub const SUB_MOVES: usize = 0x0C;            // Attacks +0x00 (4x u16)
+    pub const SUB_MOVE_PP: usize = 0x14;          // Attacks +0x08 (4x u8)
+
+    pub const SUB_EVS: usize = 0x18;              // EVs +0x00 (6x u8: HP/Atk/Def/Spe/SpA/SpD)
+    pub const SUB_CONTEST: usize = 0x1E;          // EVs +0x06 (6x u8 contest stats)
+
+    pub const SUB_POKERUS: usize = 0x24;          // Misc +0x00 (u8)
+    pub const SUB_MET_LOCATION: usize = 0x25;     // Misc +0x01 (u8)
+    pub const SUB_ORIGINS_INFO: usize = 0x26;     // Misc +0x02 (u16: met level/game/ball/OT gender)
+    pub const SUB_IV_EGG_ABILITY: usize = 0x28;   // Misc +0x04 (u32: 6 IVs x5 bits, isEgg bit, ability bit)
+}
+
+/// Which charset a GBA text string was encoded with. Selects between the
+/// Western (international) and Japanese character tables in `utils`.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Language {
+    Western,
+    Japanese,
+}
+
+/// A Pokemon's gender, derived from its personality value and species gender ratio
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Gender {
+    Male,
+    Female,
+    Genderless,
+}
+
+/// Which Gen3 title a save file belongs to. SaveBlock1's layout (party offset,
+/// how many sectors SaveBlock1 spans) differs per family; the play time
+/// offsets are the same for all three. See [`SaveLayout::for_version`].
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameVersion {
+    RubySapphire,
+    FireRedLeafGreen,
+    Emerald,
+}
+
+/// Per-version SaveBlock1 layout. Ruby/Sapphire and Emerald share a struct
+/// lineage and keep the same early-field offsets; Emerald only differs by
+/// spanning one extra sector to fit its added Pokedex/Battle Frontier data.
+/// FireRed/LeafGreen redesigned SaveBlock1 and moved the party fields much earlier.
+#[derive(Debug, Clone, Copy)]
+pub struct SaveLayout {
+    pub party_offset: usize,
+    pub party_count_offset: usize,
+    pub play_time_hours: usize,
+    pub play_time_minutes: usize,
+    pub play_time_seconds: usize,
+    /// Number of consecutive sectors (starting at logical sector id 1) SaveBlock1 spans
+    pub saveblock1_sector_count: usize,
 }
 
-// Save layout constants
-pub struct SaveLayout;
 impl SaveLayout {
-    pub const PARTY_OFFSET: usize = 0x238;
-    pub const PARTY_COUNT_OFFSET: usize = 0x234;
-    pub const PLAY_TIME_HOURS: usize = 0x0E;
-    pub const PLAY_TIME_MINUTES: usize = 0x10;
-    pub const PLAY_TIME_SECONDS: usize = 0x11;
+    pub fn for_version(version: GameVersion) -> SaveLayout {
+        match version {
+            GameVersion::RubySapphire => SaveLayout {
+                party_offset: 0x238,
+                party_count_offset: 0x234,
+                play_time_hours: 0x0E,
+                play_time_minutes: 0x10,
+                play_time_seconds: 0x11,
+                saveblock1_sector_count: 3,
+            },
+            GameVersion::FireRedLeafGreen => SaveLayout {
+                party_offset: 0x038,
+                party_count_offset: 0x034,
+                play_time_hours: 0x0E,
+                play_time_minutes: 0x10,
+                play_time_seconds: 0x11,
+                saveblock1_sector_count: 3,
+            },
+            GameVersion::Emerald => SaveLayout {
+                party_offset: 0x238,
+                party_count_offset: 0x234,
+                play_time_hours: 0x0E,
+                play_time_minutes: 0x10,
+                play_time_seconds: 0x11,
+                saveblock1_sector_count: 4,
+            },
+        }
+    }
 }
 
 #[wasm_bindgen]
@@ -1,43 +1,106 @@
-use crate::types::NATURES;
+use crate::types::{Language, NATURES};
+use std::collections::HashMap;
+use std::sync::OnceLock;
 use wasm_bindgen::prelude::*;
 
-// Character mapping for GBA Pokemon text encoding
-// This is a subset of the full character map for common characters
-// Full map would be loaded from JSON in a real implementation
-const GBA_CHAR_MAP: [(u8, char); 91] = [
-    (0x00, ' '), (0xA1, '0'), (0xA2, '1'), (0xA3, '2'), (0xA4, '3'), (0xA5, '4'),
-    (0xA6, '5'), (0xA7, '6'), (0xA8, '7'), (0xA9, '8'), (0xAA, '9'), (0xBB, 'A'),
-    (0xBC, 'B'), (0xBD, 'C'), (0xBE, 'D'), (0xBF, 'E'), (0xC0, 'F'), (0xC1, 'G'),
-    (0xC2, 'H'), (0xC3, 'I'), (0xC4, 'J'), (0xC5, 'K'), (0xC6, 'L'), (0xC7, 'M'),
-    (0xC8, 'N'), (0xC9, 'O'), (0xCA, 'P'), (0xCB, 'Q'), (0xCC, 'R'), (0xCD, 'S'),
-    (0xCE, 'T'), (0xCF, 'U'), (0xD0, 'V'), (0xD1, 'W'), (0xD2, 'X'), (0xD3, 'Y'),
-    (0xD4, 'Z'), (0xD5, 'a'), (0xD6, 'b'), (0xD7, 'c'), (0xD8, 'd'), (0xD9, 'e'),
-    (0xDA, 'f'), (0xDB, 'g'), (0xDC, 'h'), (0xDD, 'i'), (0xDE, 'j'), (0xDF, 'k'),
-    (0xE0, 'l'), (0xE1, 'm'), (0xE2, 'n'), (0xE3, 'o'), (0xE4, 'p'), (0xE5, 'q'),
-    (0xE6, 'r'), (0xE7, 's'), (0xE8, 't'), (0xE9, 'u'), (0xEA, 'v'), (0xEB, 'w'),
-    (0xEC, 'x'), (0xED, 'y'), (0xEE, 'z'), (0x34, '!'), (0x35, '?'), (0x36, '.'),
-    (0x37, '-'), (0x38, '·'), (0x39, '…'), (0x3A, '"'), (0x3B, '"'), (0x3C, '\''),
-    (0x3D, '\''), (0x3E, '♂'), (0x3F, '♀'), (0x51, '/'), (0x54, ','), (0x55, '×'),
-    (0x79, '+'), (0x7A, '%'), (0x7B, '('), (0x7C, ')'), (0x85, '&'), (0x68, ':'),
-    (0x69, ';'), (0x6A, '['), (0x6B, ']'), (0x2D, '<'), (0x2E, '>'), 
-    (0x50, ' '), (0xFF, '\0'), // Space and null terminator
+// Character mapping for GBA Pokemon text encoding, one table per `Language`.
+// The Western table follows the international Gen3 charset (accented Latin,
+// digits, the full Gen3 symbol range); the Japanese table covers the common
+// kana used in in-game names. Both are generated bidirectionally from a single
+// byte/char pair list so encode and decode always agree with each other.
+const WESTERN_CHAR_TABLE: &[(u8, char)] = &[
+    (0x00, ' '),
+    (0x01, 'À'), (0x02, 'Á'), (0x03, 'Â'), (0x04, 'Ç'), (0x05, 'È'), (0x06, 'É'),
+    (0x07, 'Ê'), (0x08, 'Ë'), (0x09, 'Ì'), (0x0B, 'Î'), (0x0C, 'Ï'), (0x0D, 'Ò'),
+    (0x0E, 'Ó'), (0x0F, 'Ô'), (0x11, 'Ù'), (0x12, 'Ú'), (0x13, 'Û'), (0x14, 'Ñ'),
+    (0x15, 'ß'), (0x16, 'à'), (0x17, 'á'), (0x19, 'ç'), (0x1A, 'è'), (0x1B, 'é'),
+    (0x1C, 'ê'), (0x1D, 'ë'), (0x1E, 'ì'), (0x21, 'î'), (0x22, 'ï'), (0x23, 'ò'),
+    (0x24, 'ó'), (0x25, 'ô'), (0x27, 'ù'), (0x28, 'ú'), (0x29, 'û'), (0x2A, 'ñ'),
+    (0x2D, '<'), (0x2E, '>'),
+    (0x34, '!'), (0x35, '?'), (0x36, '.'), (0x37, '-'), (0x38, '·'), (0x39, '…'),
+    (0x3A, '“'), (0x3B, '”'), (0x3C, '‘'), (0x3D, '’'), (0x3E, '♂'), (0x3F, '♀'),
+    (0x51, '/'), (0x54, ','), (0x55, '×'), (0x68, ':'), (0x69, ';'), (0x6A, '['),
+    (0x6B, ']'), (0x79, '+'), (0x7A, '%'), (0x7B, '('), (0x7C, ')'), (0x85, '&'),
+    (0xA1, '0'), (0xA2, '1'), (0xA3, '2'), (0xA4, '3'), (0xA5, '4'), (0xA6, '5'),
+    (0xA7, '6'), (0xA8, '7'), (0xA9, '8'), (0xAA, '9'),
+    (0xBB, 'A'), (0xBC, 'B'), (0xBD, 'C'), (0xBE, 'D'), (0xBF, 'E'), (0xC0, 'F'),
+    (0xC1, 'G'), (0xC2, 'H'), (0xC3, 'I'), (0xC4, 'J'), (0xC5, 'K'), (0xC6, 'L'),
+    (0xC7, 'M'), (0xC8, 'N'), (0xC9, 'O'), (0xCA, 'P'), (0xCB, 'Q'), (0xCC, 'R'),
+    (0xCD, 'S'), (0xCE, 'T'), (0xCF, 'U'), (0xD0, 'V'), (0xD1, 'W'), (0xD2, 'X'),
+    (0xD3, 'Y'), (0xD4, 'Z'),
+    (0xD5, 'a'), (0xD6, 'b'), (0xD7, 'c'), (0xD8, 'd'), (0xD9, 'e'), (0xDA, 'f'),
+    (0xDB, 'g'), (0xDC, 'h'), (0xDD, 'i'), (0xDE, 'j'), (0xDF, 'k'), (0xE0, 'l'),
+    (0xE1, 'm'), (0xE2, 'n'), (0xE3, 'o'), (0xE4, 'p'), (0xE5, 'q'), (0xE6, 'r'),
+    (0xE7, 's'), (0xE8, 't'), (0xE9, 'u'), (0xEA, 'v'), (0xEB, 'w'), (0xEC, 'x'),
+    (0xED, 'y'), (0xEE, 'z'),
+    (0xFF, '\0'), // terminator, never produced/consumed as a visible character
 ];
 
-/// Convert GBA-encoded bytes to a readable string
+// Seed table of common hiragana/katakana used in Japanese in-game names.
+// Extend with the remaining kana as needed.
+const JAPANESE_CHAR_TABLE: &[(u8, char)] = &[
+    (0x00, ' '),
+    (0x01, 'あ'), (0x02, 'い'), (0x03, 'う'), (0x04, 'え'), (0x05, 'お'),
+    (0x06, 'か'), (0x07, 'き'), (0x08, 'く'), (0x09, 'け'), (0x0A, 'こ'),
+    (0x0B, 'さ'), (0x0C, 'し'), (0x0D, 'す'), (0x0E, 'せ'), (0x0F, 'そ'),
+    (0x10, 'た'), (0x11, 'ち'), (0x12, 'つ'), (0x13, 'て'), (0x14, 'と'),
+    (0x15, 'な'), (0x16, 'に'), (0x17, 'ぬ'), (0x18, 'ね'), (0x19, 'の'),
+    (0x1A, 'は'), (0x1B, 'ひ'), (0x1C, 'ふ'), (0x1D, 'へ'), (0x1E, 'ほ'),
+    (0x1F, 'ま'), (0x20, 'み'), (0x21, 'む'), (0x22, 'め'), (0x23, 'も'),
+    (0x24, 'や'), (0x25, 'ゆ'), (0x26, 'よ'),
+    (0x27, 'ら'), (0x28, 'り'), (0x29, 'る'), (0x2A, 'れ'), (0x2B, 'ろ'),
+    (0x2C, 'わ'), (0x2D, 'を'), (0x2E, 'ん'),
+    (0x30, 'ア'), (0x31, 'イ'), (0x32, 'ウ'), (0x33, 'エ'), (0x34, 'オ'),
+    (0x35, 'カ'), (0x36, 'キ'), (0x37, 'ク'), (0x38, 'ケ'), (0x39, 'コ'),
+    (0x3A, 'サ'), (0x3B, 'シ'), (0x3C, 'ス'), (0x3D, 'セ'), (0x3E, 'ソ'),
+    (0x3F, 'タ'), (0x40, 'チ'), (0x41, 'ツ'), (0x42, 'テ'), (0x43, 'ト'),
+    (0xA1, '0'), (0xA2, '1'), (0xA3, '2'), (0xA4, '3'), (0xA5, '4'), (0xA6, '5'),
+    (0xA7, '6'), (0xA8, '7'), (0xA9, '8'), (0xAA, '9'),
+    (0xFF, '\0'), // terminator, never produced/consumed as a visible character
+];
+
+fn char_table(language: Language) -> &'static [(u8, char)] {
+    match language {
+        Language::Western => WESTERN_CHAR_TABLE,
+        Language::Japanese => JAPANESE_CHAR_TABLE,
+    }
+}
+
+fn byte_to_char_map(language: Language) -> &'static HashMap<u8, char> {
+    static WESTERN: OnceLock<HashMap<u8, char>> = OnceLock::new();
+    static JAPANESE: OnceLock<HashMap<u8, char>> = OnceLock::new();
+
+    let cell = match language {
+        Language::Western => &WESTERN,
+        Language::Japanese => &JAPANESE,
+    };
+    cell.get_or_init(|| char_table(language).iter().copied().collect())
+}
+
+fn char_to_byte_map(language: Language) -> &'static HashMap<char, u8> {
+    static WESTERN: OnceLock<HashMap<char, u8>> = OnceLock::new();
+    static JAPANESE: OnceLock<HashMap<char, u8>> = OnceLock::new();
+
+    let cell = match language {
+        Language::Western => &WESTERN,
+        Language::Japanese => &JAPANESE,
+    };
+    cell.get_or_init(|| char_table(language).iter().map(|&(byte, ch)| (ch, byte)).collect())
+}
+
+/// Convert GBA-encoded bytes to a readable string using the given language's charset
 #[wasm_bindgen]
-pub fn bytes_to_gba_string(bytes: &[u8]) -> String {
-    let mut result = String::new();
+pub fn bytes_to_gba_string(bytes: &[u8], language: Language) -> String {
+    let map = byte_to_char_map(language);
     let end_index = find_string_end(bytes);
-    
-    for &byte in &bytes[..end_index] {
-        if let Some((_, char)) = GBA_CHAR_MAP.iter().find(|(b, _)| *b == byte) {
-            if *char != '\0' {
-                result.push(*char);
-            }
-        }
-    }
-    
-    result.trim().to_string()
+
+    bytes[..end_index]
+        .iter()
+        .filter_map(|byte| map.get(byte).copied())
+        .filter(|&ch| ch != '\0')
+        .collect::<String>()
+        .trim()
+        .to_string()
 }
 
 /// Find the actual end of a Pokemon GBA string by detecting padding patterns
@@ -73,27 +136,29 @@ fn find_string_end(bytes: &[u8]) -> usize {
     bytes.len()
 }
 
-/// Convert a string to GBA-encoded bytes
+/// Convert a string to GBA-encoded bytes using the given language's charset,
+/// padding the rest of `length` with 0xFF. Characters outside that language's
+/// charset are dropped rather than mapped to a placeholder byte. Re-encoding a
+/// string from `bytes_to_gba_string` reproduces the original bytes only when
+/// every byte before the padding is in the charset, the name has no leading or
+/// trailing 0x00 spaces (decoding trims them), and the padding is 0xFF.
 #[wasm_bindgen]
-pub fn gba_string_to_bytes(text: &str, length: usize) -> Vec<u8> {
+pub fn gba_string_to_bytes(text: &str, length: usize, language: Language) -> Vec<u8> {
+    let map = char_to_byte_map(language);
     let mut bytes = vec![0xFF; length]; // Fill with padding
     let mut i = 0;
-    
+
     for ch in text.chars() {
         if i >= length {
             break;
         }
-        
-        // Find the byte for this character
-        if let Some((byte, _)) = GBA_CHAR_MAP.iter().find(|(_, c)| *c == ch) {
-            bytes[i] = *byte;
-            i += 1;
-        } else {
-            bytes[i] = 0x00; // Unknown character
+
+        if let Some(&byte) = map.get(&ch) {
+            bytes[i] = byte;
             i += 1;
         }
     }
-    
+
     bytes
 }
 
@@ -104,21 +169,32 @@ pub fn get_pokemon_nature(personality: u32) -> String {
     NATURES[nature_index].to_string()
 }
 
-/// Calculate sector checksum for Pokemon save data
+/// Find the personality value in the same block of 25 (same `personality / 25`)
+/// that yields the given nature (personality % 25 == nature's index in `NATURES`).
+/// A nature isn't independently stored in Gen3; it's derived from personality, so
+/// "setting" one means changing personality's remainder to the nature's index.
+/// This can shift shininess/gender, which depend on the low byte too.
+pub fn personality_for_nature(personality: u32, nature: &str) -> Option<u32> {
+    let target_index = NATURES.iter().position(|&n| n == nature)? as u32;
+    let remainder = personality % 25;
+    Some(personality - remainder + target_index)
+}
+
+/// Compute a Gen3 sector footer checksum: sum `data`'s first `data_size` bytes
+/// as wrapping 4-byte little-endian words, then fold the high 16 bits into the
+/// low 16 bits.
 #[wasm_bindgen]
-pub fn calculate_sector_checksum(sector_data: &[u8]) -> u16 {
+pub fn compute_sector_checksum(data: &[u8], data_size: usize) -> u16 {
     let mut checksum: u32 = 0;
-    
-    // Process in 4-byte chunks (little-endian u32)
-    for chunk in sector_data.chunks(4) {
+
+    for chunk in data[..data_size.min(data.len())].chunks(4) {
         if chunk.len() == 4 {
             let value = u32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
             checksum = checksum.wrapping_add(value);
         }
     }
-    
-    // Return 16-bit checksum
-    ((checksum >> 16) + (checksum & 0xFFFF)) as u16 & 0xFFFF
+
+    ((checksum >> 16) + (checksum & 0xFFFF)) as u16
 }
 
 /// Read a little-endian u16 from bytes at offset
@@ -206,6 +282,84 @@ pub fn calculate_stat(base: u16, iv: u8, ev: u8, level: u8, nature_modifier: f32
     (stat as f32 * nature_modifier) as u16
 }
 
+// Maps each of the 24 `personality % 24` shuffle orders to the physical byte-block
+// holding the Growth/Attacks/EVs/Misc substructure, e.g. order 0 ("GAEM") stores
+// Growth in block 0, Attacks in block 1, EVs in block 2, Misc in block 3.
+const SUBSTRUCTURE_ORDER: [[usize; 4]; 24] = [
+    [0, 1, 2, 3], [0, 1, 3, 2], [0, 2, 1, 3], [0, 3, 1, 2], [0, 2, 3, 1], [0, 3, 2, 1],
+    [1, 0, 2, 3], [1, 0, 3, 2], [2, 0, 1, 3], [3, 0, 1, 2], [2, 0, 3, 1], [3, 0, 2, 1],
+    [1, 2, 0, 3], [1, 3, 0, 2], [2, 1, 0, 3], [3, 1, 0, 2], [2, 3, 0, 1], [3, 2, 0, 1],
+    [1, 2, 3, 0], [1, 3, 2, 0], [2, 1, 3, 0], [3, 1, 2, 0], [2, 3, 1, 0], [3, 2, 1, 0],
+];
+
+/// Decrypt and de-shuffle the 48-byte Growth/Attacks/EVs/Misc block of a Gen3
+/// Pokemon (bytes 0x20..0x50 of the raw 100-byte structure).
+///
+/// Returns the four substructures always in canonical Growth, Attacks, EVs, Misc
+/// order (each 12 bytes), regardless of how they were physically shuffled.
+pub fn decrypt_pokemon(data: &[u8]) -> [u8; 48] {
+    let personality = read_u32_le(data, 0x00);
+    let ot_id = read_u32_le(data, 0x04);
+    let key = personality ^ ot_id;
+
+    let mut decrypted = [0u8; 48];
+    for word_index in 0..12 {
+        let word = read_u32_le(data, 0x20 + word_index * 4) ^ key;
+        decrypted[word_index * 4..word_index * 4 + 4].copy_from_slice(&word.to_le_bytes());
+    }
+
+    let order = SUBSTRUCTURE_ORDER[(personality % 24) as usize];
+    let mut ordered = [0u8; 48];
+    for (semantic_index, &physical_index) in order.iter().enumerate() {
+        let src = physical_index * 12;
+        let dst = semantic_index * 12;
+        ordered[dst..dst + 12].copy_from_slice(&decrypted[src..src + 12]);
+    }
+
+    ordered
+}
+
+/// Re-shuffle and re-encrypt a canonical-order 48-byte Growth/Attacks/EVs/Misc
+/// block back into `data`'s physical substructure layout (bytes 0x20..0x50),
+/// and update the stored header checksum (u16 at 0x1C) to match. Inverse of
+/// [`decrypt_pokemon`].
+pub fn encrypt_pokemon(data: &mut [u8], ordered: &[u8; 48]) {
+    let personality = read_u32_le(data, 0x00);
+    let ot_id = read_u32_le(data, 0x04);
+    let key = personality ^ ot_id;
+
+    let mut checksum: u16 = 0;
+    for word in ordered.chunks(2) {
+        checksum = checksum.wrapping_add(u16::from_le_bytes([word[0], word[1]]));
+    }
+    write_u16_le(data, 0x1C, checksum);
+
+    let order = SUBSTRUCTURE_ORDER[(personality % 24) as usize];
+    let mut shuffled = [0u8; 48];
+    for (semantic_index, &physical_index) in order.iter().enumerate() {
+        let src = semantic_index * 12;
+        let dst = physical_index * 12;
+        shuffled[dst..dst + 12].copy_from_slice(&ordered[src..src + 12]);
+    }
+
+    for word_index in 0..12 {
+        let plain = read_u32_le(&shuffled, word_index * 4);
+        write_u32_le(data, 0x20 + word_index * 4, plain ^ key);
+    }
+}
+
+/// Verify a decrypted Pokemon block against its stored header checksum (u16 at 0x1C):
+/// the sum of the 24 decrypted u16 words, truncated to 16 bits.
+pub fn verify_pokemon_checksum(data: &[u8]) -> bool {
+    let decrypted = decrypt_pokemon(data);
+    let mut checksum: u16 = 0;
+    for word in decrypted.chunks(2) {
+        checksum = checksum.wrapping_add(u16::from_le_bytes([word[0], word[1]]));
+    }
+
+    checksum == read_u16_le(data, 0x1C)
+}
+
 /// Get nature modifier for a stat
 pub fn get_nature_modifier(nature: &str, stat_index: u8) -> f32 {
     // Nature effects: [increased_stat, decreased_stat]
@@ -222,4 +376,79 @@ pub fn get_nature_modifier(nature: &str, stat_index: u8) -> f32 {
     if stat_index == nature_effects.0 { 1.1 }
     else if stat_index == nature_effects.1 { 0.9 }
     else { 1.0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Every byte in a char table must map to a distinct char, otherwise
+    // `char_to_byte_map`'s `.collect()` silently drops the earlier entry and
+    // `gba_string_to_bytes(bytes_to_gba_string(x))` stops round-tripping for
+    // whichever byte lost the collision.
+    #[test]
+    fn western_table_has_no_duplicate_chars() {
+        let mut seen = HashMap::new();
+        for &(byte, ch) in WESTERN_CHAR_TABLE {
+            if let Some(prev) = seen.insert(ch, byte) {
+                panic!("char {ch:?} mapped from both 0x{prev:02X} and 0x{byte:02X}");
+            }
+        }
+    }
+
+    #[test]
+    fn gba_string_round_trips_through_decode_and_encode() {
+        // The guarantee is byte-for-byte: `gba_string_to_bytes` re-encoding a
+        // decoded name must reproduce the exact original bytes, not merely
+        // decode back to an equal-looking string.
+        let original: Vec<u8> = vec![0x3A, 0x3B, 0x3C, 0x3D, 0xBB, 0xD5, 0xFF, 0xFF];
+        let text = bytes_to_gba_string(&original, Language::Western);
+        let length = original.len();
+        let encoded = gba_string_to_bytes(&text, length, Language::Western);
+
+        assert_eq!(encoded, original);
+    }
+
+    #[test]
+    fn encrypt_pokemon_is_the_inverse_of_decrypt_pokemon() {
+        let mut data = [0u8; 100];
+        write_u32_le(&mut data, 0x00, 0xDEADBEEF); // personality
+        write_u32_le(&mut data, 0x04, 0x12345678); // OT ID
+        for (i, byte) in data[0x20..0x50].iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+
+        let ordered = decrypt_pokemon(&data);
+
+        // Re-encrypting the decrypted block back into a fresh copy of the
+        // header must reproduce the original encrypted/shuffled bytes exactly
+        // (encrypt_pokemon also (re)writes the header checksum, so seed it
+        // with the same value first).
+        let mut expected = data;
+        encrypt_pokemon(&mut expected, &ordered);
+        let stored_checksum = read_u16_le(&expected, 0x1C);
+        write_u16_le(&mut data, 0x1C, stored_checksum);
+
+        let mut round_tripped = data;
+        encrypt_pokemon(&mut round_tripped, &ordered);
+
+        assert_eq!(round_tripped, data);
+        assert_eq!(decrypt_pokemon(&round_tripped), ordered);
+        assert!(verify_pokemon_checksum(&round_tripped));
+    }
+
+    #[test]
+    fn compute_sector_checksum_sums_le_words_and_folds_high_bits() {
+        // Two 4-byte LE words: 0x00010000 and 0x00000001, summing to
+        // 0x00010001; folding its high 16 bits into its low 16 bits must
+        // produce 0x0002, not the raw 32-bit sum.
+        let data = [0x00, 0x00, 0x01, 0x00, 0x01, 0x00, 0x00, 0x00];
+        assert_eq!(compute_sector_checksum(&data, data.len()), 0x0002);
+    }
+
+    #[test]
+    fn compute_sector_checksum_ignores_bytes_past_data_size() {
+        let data = [0x01, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF];
+        assert_eq!(compute_sector_checksum(&data, 4), 1);
+    }
 }
\ No newline at end of file